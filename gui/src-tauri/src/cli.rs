@@ -0,0 +1,168 @@
+// Headless entry point: `xliff-regex-tool <subcommand> ...` skips
+// `tauri::Builder` entirely and runs a check/replace/validate pass against
+// a profile and an XLIFF file, so a localization CI job can gate on it
+// without a display. Reuses the same profile-loading and matching
+// functions the GUI commands call.
+use clap::{Parser, Subcommand};
+
+use crate::{load_qa_profile, validate_qa_profile, xliff};
+
+#[derive(Parser)]
+#[command(name = "xliff-regex-tool", about = "QA-check XLIFF files against a regex profile")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Known subcommand names, kept in sync with `Command`'s variants (clap
+/// renders them kebab-case by default).
+const SUBCOMMANDS: &[&str] = &["check", "list-profiles", "replace", "validate"];
+
+/// Whether `args` (the process's argv, minus argv[0]) looks like a
+/// deliberate invocation of this CLI rather than incidental args the OS
+/// handed the bundled app — a file path from a file-association/double
+/// click, macOS's `-psn_...`, etc. Only a recognized subcommand or the
+/// explicit `--cli` escape hatch counts; anything else should fall through
+/// to the GUI instead of erroring out of a launch the user didn't ask to be
+/// headless.
+pub fn looks_like_invocation(args: &[String]) -> bool {
+    match args.first() {
+        Some(arg) => arg == "--cli" || SUBCOMMANDS.contains(&arg.as_str()),
+        None => false,
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a QA profile's checks against an XLIFF file and report matches
+    Check {
+        #[arg(long)]
+        profile: String,
+        file: String,
+    },
+    /// List *_qa_profile.xml and *_qa_profile.json files in a directory
+    ListProfiles {
+        #[arg(long, default_value = "samples")]
+        dir: String,
+    },
+    /// Apply a QA profile's replacements to an XLIFF file in place
+    Replace {
+        #[arg(long)]
+        profile: String,
+        file: String,
+    },
+    /// Compile every pattern in a QA profile and report syntax errors
+    Validate { profile: String },
+}
+
+pub fn run(args: &[String]) -> Result<i32, String> {
+    let cli = Cli::try_parse_from(std::iter::once("xliff-regex-tool".to_string()).chain(args.iter().cloned()))
+        .map_err(|e| e.to_string())?;
+
+    match cli.command {
+        Command::Check { profile, file } => run_check(&profile, &file),
+        Command::ListProfiles { dir } => run_list_profiles(&dir),
+        Command::Replace { profile, file } => run_replace(&profile, &file),
+        Command::Validate { profile } => run_validate(&profile),
+    }
+}
+
+fn run_check(profile_path: &str, file_path: &str) -> Result<i32, String> {
+    let content = std::fs::read_to_string(file_path)
+        .map_err(|e| format!("Failed to read {}: {}", file_path, e))?;
+    let profile = load_qa_profile(profile_path.to_string())?;
+    let result = xliff::find_matches(file_path, &content, &profile)?;
+
+    println!("{}: {} match(es)", result.file, result.total_matches);
+    for m in &result.matches {
+        println!("  [{}] {} in unit {}: \"{}\"", m.check_name, m.category, m.tu_id, m.match_text);
+    }
+
+    Ok(if result.total_matches > 0 { 1 } else { 0 })
+}
+
+fn run_list_profiles(dir: &str) -> Result<i32, String> {
+    let dir_path = std::path::Path::new(dir);
+    if !dir_path.exists() {
+        println!("No profiles directory at {}", dir);
+        return Ok(0);
+    }
+
+    let mut found = 0;
+    for entry in std::fs::read_dir(dir_path)
+        .map_err(|e| format!("Failed to read {}: {}", dir, e))?
+        .flatten()
+    {
+        let path = entry.path();
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            if name.ends_with("_qa_profile.xml") || name.ends_with("_qa_profile.json") {
+                found += 1;
+                println!("{}", path.display());
+            }
+        }
+    }
+
+    if found == 0 {
+        println!("No QA profiles found in {}", dir);
+    }
+
+    Ok(0)
+}
+
+fn run_replace(profile_path: &str, file_path: &str) -> Result<i32, String> {
+    let content = std::fs::read_to_string(file_path)
+        .map_err(|e| format!("Failed to read {}: {}", file_path, e))?;
+    let profile = load_qa_profile(profile_path.to_string())?;
+    let (new_content, result) = xliff::apply_replacements(&content, &profile)?;
+
+    std::fs::write(file_path, new_content)
+        .map_err(|e| format!("Failed to write {}: {}", file_path, e))?;
+
+    println!(
+        "{}: {} unit(s) modified, {} replacement(s)",
+        file_path, result.modified_units, result.total_replacements
+    );
+
+    Ok(0)
+}
+
+fn run_validate(profile_path: &str) -> Result<i32, String> {
+    let diagnostics = validate_qa_profile(profile_path.to_string())?;
+
+    for d in &diagnostics {
+        println!("  [check \"{}\", order {}] {}: {}", d.check_name, d.check_order, d.pattern, d.message);
+    }
+
+    if diagnostics.is_empty() {
+        println!("{}: all checks valid", profile_path);
+    } else {
+        println!("{}: {} invalid pattern(s)", profile_path, diagnostics.len());
+    }
+
+    Ok(if diagnostics.is_empty() { 0 } else { 1 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(strs: &[&str]) -> Vec<String> {
+        strs.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn recognizes_known_subcommands_and_the_escape_hatch() {
+        assert!(looks_like_invocation(&args(&["check", "--profile", "p.xml", "f.xlf"])));
+        assert!(looks_like_invocation(&args(&["list-profiles"])));
+        assert!(looks_like_invocation(&args(&["replace", "--profile", "p.xml", "f.xlf"])));
+        assert!(looks_like_invocation(&args(&["validate", "p.xml"])));
+        assert!(looks_like_invocation(&args(&["--cli", "validate", "p.xml"])));
+    }
+
+    #[test]
+    fn ignores_args_the_os_tacked_on_to_a_gui_launch() {
+        assert!(!looks_like_invocation(&args(&[])));
+        assert!(!looks_like_invocation(&args(&["-psn_0_123456"])));
+        assert!(!looks_like_invocation(&args(&["/Users/x/Documents/file.xlf"])));
+    }
+}
@@ -1,45 +1,36 @@
+mod cli;
+pub mod regex_compat;
+pub mod xliff;
+
 use std::process::Command;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use tauri::{Manager, menu::{MenuBuilder, MenuItemBuilder, SubmenuBuilder, CheckMenuItemBuilder, PredefinedMenuItem}, Listener};
 use tauri::Emitter;
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Metadata {
-    match_percent: Option<String>,
-    match_quality: Option<String>,
-    translate: Option<String>,
-    approved: Option<String>,
-    modified_date: Option<String>,
-    modified_by: Option<String>,
-    state: Option<String>,
-    locked: Option<String>,
-    created_date: Option<String>,
-    created_by: Option<String>,
-    origin: Option<String>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct TransUnit {
-    id: String,
-    source: String,
-    target: String,
-    metadata: Option<Metadata>,
-    icu_errors: Option<Vec<String>>,
-}
+use xliff::{BatchFindResult, BatchReplaceResult, EditedUnit, XliffData};
 
-#[derive(Debug, Serialize, Deserialize)]
-struct XliffData {
-    trans_units: Vec<TransUnit>,
-    stats: Stats,
+/// Set to force the legacy `src/cli.py` subprocess path instead of the
+/// native engine, e.g. while the Rust port is still being validated against
+/// a corpus of real-world files.
+fn use_cli_fallback() -> bool {
+    std::env::var("XLIFF_REGEX_TOOL_USE_CLI").is_ok()
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Stats {
-    total_units: i32,
-    translated: i32,
-    untranslated: i32,
+/// Resolve the XLIFF content a command should operate on: prefer an
+/// in-memory buffer (pasted/unsaved documents, library callers) and fall
+/// back to reading `file_path` from disk.
+fn resolve_content(file_path: &Option<String>, content: &Option<String>) -> Result<String, String> {
+    if let Some(content) = content {
+        return Ok(content.clone());
+    }
+    let file_path = file_path
+        .as_ref()
+        .ok_or_else(|| "Either file_path or content must be provided".to_string())?;
+    fs::read_to_string(file_path).map_err(|e| format!("Failed to read file: {}", e))
 }
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
@@ -97,20 +88,34 @@ fn get_changelog_content(app_handle: tauri::AppHandle) -> Result<String, String>
     let markdown = std::fs::read_to_string(&path)
         .map_err(|e| format!("Failed to read changelog: {}", e))?;
 
-    // Convert Markdown to HTML using a simple approach
-    // For now, just wrap in pre tags to preserve formatting
+    // Render headings, lists, code blocks and links properly instead of
+    // dumping everything into a <pre> block.
+    let parser = pulldown_cmark::Parser::new(&markdown);
+    let mut body = String::new();
+    pulldown_cmark::html::push_html(&mut body, parser);
+
     let html = format!(
-        r#"<div style="font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; line-height: 1.6; max-width: 900px;">
-        <pre style="white-space: pre-wrap; word-wrap: break-word; font-family: inherit; background: none; padding: 0; color: #1c1c1e;">{}</pre>
-        </div>"#,
-        markdown
+        r#"<div style="font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; line-height: 1.6; max-width: 900px; color: #1c1c1e;">{}</div>"#,
+        body
     );
 
     Ok(html)
 }
 
 #[tauri::command]
-fn open_xliff(file_path: String, app_handle: tauri::AppHandle) -> Result<XliffData, String> {
+fn open_xliff(file_path: Option<String>, content: Option<String>, app_handle: tauri::AppHandle) -> Result<XliffData, String> {
+    if use_cli_fallback() {
+        let file_path = file_path
+            .ok_or_else(|| "The CLI fallback only supports file_path, not raw content".to_string())?;
+        return open_xliff_via_cli(&file_path, &app_handle);
+    }
+
+    let content = resolve_content(&file_path, &content)?;
+
+    xliff::parse_xliff(&content)
+}
+
+fn open_xliff_via_cli(file_path: &str, app_handle: &tauri::AppHandle) -> Result<XliffData, String> {
     // Determine CLI executable path based on environment
     let cli_path = if cfg!(dev) {
         // Development mode: use Python script directly
@@ -122,7 +127,7 @@ fn open_xliff(file_path: String, app_handle: tauri::AppHandle) -> Result<XliffDa
         let output = Command::new(&python)
             .arg(&script)
             .arg("stats")
-            .arg(&file_path)
+            .arg(file_path)
             .arg("--json")
             .output()
             .map_err(|e| format!("Failed to execute Python: {}", e))?;
@@ -149,7 +154,7 @@ fn open_xliff(file_path: String, app_handle: tauri::AppHandle) -> Result<XliffDa
     // Call CLI executable to parse XLIFF file
     let output = Command::new(&cli_path)
         .arg("stats")
-        .arg(&file_path)
+        .arg(file_path)
         .arg("--json")
         .output()
         .map_err(|e| format!("Failed to execute CLI: {}", e))?;
@@ -168,15 +173,45 @@ fn open_xliff(file_path: String, app_handle: tauri::AppHandle) -> Result<XliffDa
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct EditedUnit {
-    id: String,
-    target: String,
+struct SaveXliffResult {
+    message: String,
+    content: Option<String>,
 }
 
 #[tauri::command]
-fn save_xliff(file_path: String, edited_units: Vec<EditedUnit>, app_handle: tauri::AppHandle) -> Result<String, String> {
-    use std::fs;
+fn save_xliff(
+    file_path: Option<String>,
+    content: Option<String>,
+    edited_units: Vec<EditedUnit>,
+    app_handle: tauri::AppHandle,
+) -> Result<SaveXliffResult, String> {
+    if use_cli_fallback() {
+        let file_path = file_path
+            .ok_or_else(|| "The CLI fallback only supports file_path, not raw content".to_string())?;
+        let message = save_xliff_via_cli(&file_path, edited_units, &app_handle)?;
+        return Ok(SaveXliffResult { message, content: None });
+    }
 
+    let source = resolve_content(&file_path, &content)?;
+    let updated = xliff::apply_edits(&source, &edited_units)?;
+
+    match file_path {
+        Some(file_path) => {
+            fs::write(&file_path, updated)
+                .map_err(|e| format!("Failed to write file: {}", e))?;
+            Ok(SaveXliffResult {
+                message: "File saved successfully".to_string(),
+                content: None,
+            })
+        }
+        None => Ok(SaveXliffResult {
+            message: "Edits applied in memory".to_string(),
+            content: Some(updated),
+        }),
+    }
+}
+
+fn save_xliff_via_cli(file_path: &str, edited_units: Vec<EditedUnit>, app_handle: &tauri::AppHandle) -> Result<String, String> {
     // Create temporary JSON file with edits
     let temp_json = format!("/tmp/xliff_edits_{}.json", std::process::id());
     let json_data = serde_json::to_string(&edited_units)
@@ -234,6 +269,11 @@ struct RegexEntry {
     pattern: String,
     replace: String,
     category: String,
+    /// Name (file stem) of the library file this entry was loaded from, so
+    /// the UI can show provenance and let a whole library be toggled off.
+    /// Empty when the entry hasn't been saved to a library file yet.
+    #[serde(default)]
+    source: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -247,57 +287,154 @@ struct RegexLibrary {
     categories: Vec<RegexCategory>,
 }
 
-fn get_library_path() -> Result<PathBuf, String> {
+/// The file a plain `save_regex_library` call writes to. Libraries loaded
+/// from any other file in the directory are treated as read-only defaults
+/// that `user.xml` can override, entry by entry, within the same category.
+const USER_LIBRARY_FILE: &str = "user.xml";
+
+fn get_libraries_dir() -> Result<PathBuf, String> {
     let home = std::env::var("HOME")
         .map_err(|_| "Failed to get home directory".to_string())?;
-    let lib_dir = PathBuf::from(home).join(".xliff-regex-tool");
+    let base_dir = PathBuf::from(home).join(".xliff-regex-tool");
+    let libraries_dir = base_dir.join("libraries");
+
+    if !libraries_dir.exists() {
+        fs::create_dir_all(&libraries_dir)
+            .map_err(|e| format!("Failed to create libraries directory: {}", e))?;
+
+        // Migrate a pre-existing single-file library so upgrading doesn't
+        // silently drop a user's saved patterns.
+        let legacy_path = base_dir.join("library.xml");
+        if legacy_path.exists() {
+            let _ = fs::copy(&legacy_path, libraries_dir.join("legacy.xml"));
+        }
+    }
+
+    Ok(libraries_dir)
+}
 
-    // Create directory if it doesn't exist
-    if !lib_dir.exists() {
-        fs::create_dir_all(&lib_dir)
-            .map_err(|e| format!("Failed to create library directory: {}", e))?;
+fn default_library() -> RegexLibrary {
+    RegexLibrary {
+        categories: vec![
+            RegexCategory {
+                name: "Tegnsetting".to_string(),
+                entries: vec![],
+            },
+            RegexCategory {
+                name: "Harde mellomrom".to_string(),
+                entries: vec![],
+            },
+            RegexCategory {
+                name: "Tall/tallformatering".to_string(),
+                entries: vec![],
+            },
+            RegexCategory {
+                name: "Spesialtegn".to_string(),
+                entries: vec![],
+            },
+        ],
     }
+}
 
-    Ok(lib_dir.join("library.xml"))
+/// Merge `incoming` categories into `target`, matching categories by name
+/// and entries within them by name. A later library's entry overrides an
+/// earlier one of the same name; anything new is appended.
+fn merge_library_categories(target: &mut Vec<RegexCategory>, incoming: Vec<RegexCategory>) {
+    for category in incoming {
+        match target.iter_mut().find(|c| c.name == category.name) {
+            Some(existing) => {
+                for entry in category.entries {
+                    match existing.entries.iter_mut().find(|e| e.name == entry.name) {
+                        Some(slot) => *slot = entry,
+                        None => existing.entries.push(entry),
+                    }
+                }
+            }
+            None => target.push(category),
+        }
+    }
 }
 
 #[tauri::command]
 fn load_regex_library() -> Result<RegexLibrary, String> {
-    let lib_path = get_library_path()?;
-
-    // If file doesn't exist, return default library with standard categories
-    if !lib_path.exists() {
-        return Ok(RegexLibrary {
-            categories: vec![
-                RegexCategory {
-                    name: "Tegnsetting".to_string(),
-                    entries: vec![],
-                },
-                RegexCategory {
-                    name: "Harde mellomrom".to_string(),
-                    entries: vec![],
-                },
-                RegexCategory {
-                    name: "Tall/tallformatering".to_string(),
-                    entries: vec![],
-                },
-                RegexCategory {
-                    name: "Spesialtegn".to_string(),
-                    entries: vec![],
-                },
-            ],
-        });
+    let libraries_dir = get_libraries_dir()?;
+
+    let mut files: Vec<PathBuf> = fs::read_dir(&libraries_dir)
+        .map_err(|e| format!("Failed to read libraries directory: {}", e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| matches!(path.extension().and_then(|ext| ext.to_str()), Some("xml") | Some("json")))
+        .collect();
+    // Alphabetical, except that `user.xml` always merges last so it
+    // overrides every other library file.
+    files.sort_by_key(|path| {
+        let is_user = path.file_name().and_then(|n| n.to_str()) == Some(USER_LIBRARY_FILE);
+        (is_user, path.clone())
+    });
+
+    if files.is_empty() {
+        return Ok(default_library());
     }
 
-    // Read and parse XML
-    let xml_content = fs::read_to_string(&lib_path)
-        .map_err(|e| format!("Failed to read library file: {}", e))?;
+    let mut categories: Vec<RegexCategory> = Vec::new();
+    for path in files {
+        let source = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read library file {}: {}", path.display(), e))?;
+        let library = if is_json_path(&path) {
+            parse_library_json(&content, &source)?
+        } else {
+            parse_library_xml(&content, &source)?
+        };
+        merge_library_categories(&mut categories, library.categories);
+    }
 
-    // Parse XML using quick-xml
-    parse_library_xml(&xml_content)
+    Ok(RegexLibrary { categories })
 }
 
-fn parse_library_xml(xml: &str) -> Result<RegexLibrary, String> {
+/// Compile every entry's (translated) pattern and return one message per
+/// entry that doesn't compile, so an import can be rejected with
+/// every broken pattern named instead of failing opaquely on first use.
+fn validate_library_patterns(library: &RegexLibrary) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    for category in &library.categories {
+        for entry in &category.entries {
+            let result = regex_compat::validate(&entry.pattern, &entry.replace);
+            if let Some(error) = result.error {
+                problems.push(format!("{} / {}: {}", category.name, entry.name, error.message));
+            }
+        }
+    }
+
+    problems
+}
+
+/// JSON counterpart to `parse_library_xml`: deserialize a `RegexLibrary` and
+/// stamp every entry's `source`/`category`/`id` the same way the XML parser
+/// does, so a hand-authored JSON library doesn't need to carry them.
+fn parse_library_json(json: &str, source: &str) -> Result<RegexLibrary, String> {
+    let mut library: RegexLibrary = serde_json::from_str(json)
+        .map_err(|e| format!("JSON parse error: {}", e))?;
+
+    for category in &mut library.categories {
+        for entry in &mut category.entries {
+            if entry.id.is_empty() {
+                entry.id = uuid::Uuid::new_v4().to_string();
+            }
+            entry.category = category.name.clone();
+            entry.source = source.to_string();
+        }
+    }
+
+    Ok(library)
+}
+
+fn parse_library_xml(xml: &str, source: &str) -> Result<RegexLibrary, String> {
     use quick_xml::events::Event;
     use quick_xml::Reader;
 
@@ -333,6 +470,7 @@ fn parse_library_xml(xml: &str) -> Result<RegexLibrary, String> {
                             pattern: String::new(),
                             replace: String::new(),
                             category: current_category.as_ref().map(|c| c.name.clone()).unwrap_or_default(),
+                            source: source.to_string(),
                         });
                     }
                     b"name" | b"description" | b"pattern" | b"replace" => {
@@ -381,16 +519,12 @@ fn parse_library_xml(xml: &str) -> Result<RegexLibrary, String> {
     Ok(RegexLibrary { categories })
 }
 
-#[tauri::command]
-fn save_regex_library(library: RegexLibrary) -> Result<String, String> {
-    let lib_path = get_library_path()?;
-
-    // Build XML
+fn build_library_xml(library: &RegexLibrary) -> String {
     let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<regex-library>\n");
 
-    for category in library.categories {
+    for category in &library.categories {
         xml.push_str(&format!("  <category name=\"{}\">\n", escape_xml(&category.name)));
-        for entry in category.entries {
+        for entry in &category.entries {
             xml.push_str("    <entry>\n");
             xml.push_str(&format!("      <name>{}</name>\n", escape_xml(&entry.name)));
             xml.push_str(&format!("      <description>{}</description>\n", escape_xml(&entry.description)));
@@ -402,14 +536,78 @@ fn save_regex_library(library: RegexLibrary) -> Result<String, String> {
     }
 
     xml.push_str("</regex-library>\n");
+    xml
+}
 
-    // Write file
-    fs::write(&lib_path, xml)
+#[tauri::command]
+fn save_regex_library(library: RegexLibrary) -> Result<String, String> {
+    let libraries_dir = get_libraries_dir()?;
+    let xml = build_library_xml(&library);
+
+    fs::write(libraries_dir.join(USER_LIBRARY_FILE), xml)
         .map_err(|e| format!("Failed to write library file: {}", e))?;
 
     Ok("Library saved successfully".to_string())
 }
 
+#[tauri::command]
+fn import_library_file(import_path: String) -> Result<String, String> {
+    let source_path = PathBuf::from(&import_path);
+    let xml_content = fs::read_to_string(&source_path)
+        .map_err(|e| format!("Failed to read import file: {}", e))?;
+
+    let file_name = source_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| "Import path has no file name".to_string())?
+        .to_string();
+    let stem = source_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("imported");
+
+    // Validate before copying it into the directory the loader scans.
+    let library = if is_json_path(&source_path) {
+        parse_library_json(&xml_content, stem)?
+    } else {
+        parse_library_xml(&xml_content, stem)?
+    };
+    let problems = validate_library_patterns(&library);
+    if !problems.is_empty() {
+        return Err(format!("Invalid pattern(s) in {}: {}", file_name, problems.join("; ")));
+    }
+
+    let libraries_dir = get_libraries_dir()?;
+    let destination = libraries_dir.join(&file_name);
+    fs::write(&destination, xml_content)
+        .map_err(|e| format!("Failed to import library file: {}", e))?;
+
+    Ok(file_name)
+}
+
+#[tauri::command]
+fn export_regex_category(category_name: String, export_path: String) -> Result<String, String> {
+    let library = load_regex_library()?;
+    let category = library
+        .categories
+        .into_iter()
+        .find(|c| c.name == category_name)
+        .ok_or_else(|| format!("Category '{}' not found", category_name))?;
+
+    let single = RegexLibrary { categories: vec![category] };
+    let content = if is_json_path(std::path::Path::new(&export_path)) {
+        serde_json::to_string_pretty(&single)
+            .map_err(|e| format!("Failed to serialize category: {}", e))?
+    } else {
+        build_library_xml(&single)
+    };
+
+    fs::write(&export_path, content)
+        .map_err(|e| format!("Failed to export category: {}", e))?;
+
+    Ok(format!("Category exported successfully to {}", export_path))
+}
+
 fn escape_xml(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -418,33 +616,73 @@ fn escape_xml(s: &str) -> String {
         .replace('\'', "&apos;")
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct BatchFindMatch {
-    tu_id: String,
-    check_name: String,
-    check_order: i32,
-    category: String,
-    description: String,
-    source: String,
-    target: String,
-    #[serde(rename = "match")]
-    match_text: String,
-    match_start: i32,
-    match_end: i32,
-    pattern: String,
-    replacement: String,
-}
+#[tauri::command]
+fn batch_find(
+    file_path: Option<String>,
+    content: Option<String>,
+    profile_path: String,
+    app_handle: tauri::AppHandle,
+) -> Result<BatchFindResult, String> {
+    if use_cli_fallback() {
+        let file_path = file_path
+            .ok_or_else(|| "The CLI fallback only supports file_path, not raw content".to_string())?;
+        return batch_find_via_cli(&file_path, &profile_path, &app_handle);
+    }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct BatchFindResult {
-    profile_name: String,
-    file: String,
-    total_matches: i32,
-    matches: Vec<BatchFindMatch>,
+    let source = resolve_content(&file_path, &content)?;
+    let profile = load_qa_profile(profile_path)?;
+    let label = file_path.unwrap_or_else(|| "<in-memory>".to_string());
+    let xliff_data = xliff::parse_xliff(&source)?;
+    let total = xliff_data.trans_units.len() as i32;
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let cancel_flag = cancelled.clone();
+    let cancel_listener = app_handle.listen("batch-cancel", move |_| {
+        cancel_flag.store(true, Ordering::SeqCst);
+    });
+
+    let worker_app = app_handle.clone();
+    let worker_profile = profile;
+    let worker_label = label;
+    let handle = std::thread::spawn(move || {
+        let mut matches = Vec::new();
+        let mut processed = 0;
+
+        for tu in &xliff_data.trans_units {
+            if cancelled.load(Ordering::SeqCst) {
+                break;
+            }
+
+            matches.extend(xliff::check_unit(tu, &worker_profile));
+            processed += 1;
+
+            let _ = worker_app.emit(
+                "batch-progress",
+                serde_json::json!({
+                    "processed": processed,
+                    "total": total,
+                    "matches": matches.len(),
+                }),
+            );
+        }
+
+        BatchFindResult {
+            profile_name: worker_profile.name.clone(),
+            file: worker_label,
+            total_matches: matches.len() as i32,
+            matches,
+        }
+    });
+
+    let result = handle
+        .join()
+        .map_err(|_| "Batch find worker thread panicked".to_string())?;
+    app_handle.unlisten(cancel_listener);
+
+    Ok(result)
 }
 
-#[tauri::command]
-fn batch_find(file_path: String, profile_path: String, app_handle: tauri::AppHandle) -> Result<BatchFindResult, String> {
+fn batch_find_via_cli(file_path: &str, profile_path: &str, app_handle: &tauri::AppHandle) -> Result<BatchFindResult, String> {
     // Determine CLI executable path based on environment
     let output = if cfg!(dev) {
         // Development mode: use Python script directly
@@ -515,19 +753,29 @@ fn list_qa_profiles(app_handle: tauri::AppHandle) -> Result<Vec<QAProfileInfo>,
 
     let mut profiles = Vec::new();
 
-    // Find all *_qa_profile.xml files
+    // Find all *_qa_profile.xml and *_qa_profile.json files
     if let Ok(entries) = fs::read_dir(&profiles_dir) {
         for entry in entries.flatten() {
             let path = entry.path();
             if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
-                if filename.ends_with("_qa_profile.xml") {
-                    // Try to parse basic metadata
-                    if let Ok(content) = fs::read_to_string(&path) {
-                        // Simple XML parsing to extract metadata
-                        let name = extract_xml_tag(&content, "name").unwrap_or_else(|| filename.to_string());
-                        let description = extract_xml_tag(&content, "description").unwrap_or_default();
-                        let language = extract_xml_tag(&content, "language").unwrap_or_default();
-
+                if filename.ends_with("_qa_profile.xml") || filename.ends_with("_qa_profile.json") {
+                    let info = if is_json_path(&path) {
+                        fs::read_to_string(&path).ok().and_then(|content| {
+                            serde_json::from_str::<QAProfileData>(&content).ok().map(|p| {
+                                (p.name, p.description, p.language)
+                            })
+                        })
+                    } else {
+                        fs::read_to_string(&path).ok().map(|content| {
+                            (
+                                extract_xml_tag(&content, "name").unwrap_or_else(|| filename.to_string()),
+                                extract_xml_tag(&content, "description").unwrap_or_default(),
+                                extract_xml_tag(&content, "language").unwrap_or_default(),
+                            )
+                        })
+                    };
+
+                    if let Some((name, description, language)) = info {
                         profiles.push(QAProfileInfo {
                             path: path.to_string_lossy().to_string(),
                             name,
@@ -557,16 +805,96 @@ fn extract_xml_tag(xml: &str, tag: &str) -> Option<String> {
     None
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct BatchReplaceResult {
-    success: bool,
-    modified_units: i32,
-    total_replacements: i32,
-    output_path: String,
+#[tauri::command]
+fn batch_replace(
+    file_path: Option<String>,
+    content: Option<String>,
+    profile_path: String,
+    app_handle: tauri::AppHandle,
+) -> Result<BatchReplaceResult, String> {
+    if use_cli_fallback() {
+        let file_path = file_path
+            .ok_or_else(|| "The CLI fallback only supports file_path, not raw content".to_string())?;
+        return batch_replace_via_cli(&file_path, &profile_path, &app_handle);
+    }
+
+    let source = resolve_content(&file_path, &content)?;
+    let profile = load_qa_profile(profile_path)?;
+    let xliff_data = xliff::parse_xliff(&source)?;
+    let total = xliff_data.trans_units.len() as i32;
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let cancel_flag = cancelled.clone();
+    let cancel_listener = app_handle.listen("batch-cancel", move |_| {
+        cancel_flag.store(true, Ordering::SeqCst);
+    });
+
+    let worker_app = app_handle.clone();
+    let worker_profile = profile;
+    let handle = std::thread::spawn(move || {
+        let mut modified_units = 0;
+        let mut total_replacements = 0;
+        let mut edits = Vec::new();
+        let mut processed = 0;
+
+        for tu in &xliff_data.trans_units {
+            if cancelled.load(Ordering::SeqCst) {
+                break;
+            }
+
+            if let Some((new_target, count)) = xliff::replace_unit(tu, &worker_profile) {
+                modified_units += 1;
+                total_replacements += count;
+                edits.push(xliff::EditedUnit {
+                    id: tu.id.clone(),
+                    target: new_target,
+                });
+            }
+            processed += 1;
+
+            let _ = worker_app.emit(
+                "batch-progress",
+                serde_json::json!({
+                    "processed": processed,
+                    "total": total,
+                    "matches": total_replacements,
+                }),
+            );
+        }
+
+        (modified_units, total_replacements, edits)
+    });
+
+    let (modified_units, total_replacements, edits) = handle
+        .join()
+        .map_err(|_| "Batch replace worker thread panicked".to_string())?;
+    app_handle.unlisten(cancel_listener);
+
+    let new_content = xliff::apply_edits(&source, &edits)?;
+
+    let mut result = BatchReplaceResult {
+        success: true,
+        modified_units,
+        total_replacements,
+        output_path: String::new(),
+        content: None,
+    };
+
+    match file_path {
+        Some(file_path) => {
+            fs::write(&file_path, &new_content)
+                .map_err(|e| format!("Failed to write file: {}", e))?;
+            result.output_path = file_path;
+        }
+        None => {
+            result.content = Some(new_content);
+        }
+    }
+
+    Ok(result)
 }
 
-#[tauri::command]
-fn batch_replace(file_path: String, profile_path: String, app_handle: tauri::AppHandle) -> Result<BatchReplaceResult, String> {
+fn batch_replace_via_cli(file_path: &str, profile_path: &str, app_handle: &tauri::AppHandle) -> Result<BatchReplaceResult, String> {
     // Determine CLI executable path based on environment
     let output = if cfg!(dev) {
         // Development mode: use Python script directly
@@ -614,16 +942,20 @@ fn batch_replace(file_path: String, profile_path: String, app_handle: tauri::App
     Ok(data)
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct QAProfileData {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct QAProfileData {
     name: String,
     description: String,
     language: String,
     checks: Vec<QACheckData>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct QACheckData {
+fn default_check_kind() -> String {
+    "regex".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct QACheckData {
     order: i32,
     enabled: bool,
     name: String,
@@ -633,30 +965,28 @@ struct QACheckData {
     category: String,
     case_sensitive: bool,
     exclude_pattern: String,
+    /// Either `"regex"` (the default, matched against `pattern`/`replacement`)
+    /// or `"external"`, which instead runs `command` with `args` against the
+    /// segment for checks a single regex can't express (terminology lookups,
+    /// spell/grammar rules).
+    #[serde(default = "default_check_kind")]
+    kind: String,
+    #[serde(default)]
+    command: String,
+    /// Whitespace-separated argument template; `{source}`/`{target}` are
+    /// substituted with paths to temp files holding the segment text.
+    #[serde(default)]
+    args: String,
 }
 
-#[tauri::command]
-fn save_qa_profile(profile_data: QAProfileData, file_name: String, app_handle: tauri::AppHandle) -> Result<String, String> {
-    // Determine save path
-    let profiles_dir = if cfg!(dev) {
-        PathBuf::from("../../samples")
-    } else {
-        let resource_dir = app_handle.path()
-            .resource_dir()
-            .map_err(|e| format!("Failed to get resource directory: {}", e))?;
-        resource_dir.join("_up_/_up_/samples")
-    };
-
-    // Ensure directory exists
-    if !profiles_dir.exists() {
-        fs::create_dir_all(&profiles_dir)
-            .map_err(|e| format!("Failed to create profiles directory: {}", e))?;
-    }
-
-    // Build file path
-    let file_path = profiles_dir.join(&file_name);
+fn is_json_path(path: &std::path::Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("json"))
+        .unwrap_or(false)
+}
 
-    // Build XML
+fn build_profile_xml(profile_data: &QAProfileData, created: &str, modified: &str) -> String {
     let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<qa_profile>\n");
 
     // Metadata
@@ -664,20 +994,13 @@ fn save_qa_profile(profile_data: QAProfileData, file_name: String, app_handle: t
     xml.push_str(&format!("        <name>{}</name>\n", escape_xml(&profile_data.name)));
     xml.push_str(&format!("        <description>{}</description>\n", escape_xml(&profile_data.description)));
     xml.push_str(&format!("        <language>{}</language>\n", escape_xml(&profile_data.language)));
-
-    // Add timestamp
-    use std::time::SystemTime;
-    let now = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap();
-    let date = format!("{}", now.as_secs() / 86400 * 86400); // Simple date approximation
-    xml.push_str(&format!("        <created>{}</created>\n", date));
-    xml.push_str(&format!("        <modified>{}</modified>\n", date));
+    xml.push_str(&format!("        <created>{}</created>\n", escape_xml(created)));
+    xml.push_str(&format!("        <modified>{}</modified>\n", escape_xml(modified)));
     xml.push_str("    </metadata>\n\n");
 
     // Checks
     xml.push_str("    <checks>\n");
-    for check in profile_data.checks {
+    for check in &profile_data.checks {
         xml.push_str(&format!("        <check order=\"{}\" enabled=\"{}\">\n",
             check.order,
             if check.enabled { "true" } else { "false" }
@@ -691,11 +1014,63 @@ fn save_qa_profile(profile_data: QAProfileData, file_name: String, app_handle: t
             if check.case_sensitive { "true" } else { "false" }
         ));
         xml.push_str(&format!("            <exclude_pattern>{}</exclude_pattern>\n", escape_xml(&check.exclude_pattern)));
+        xml.push_str(&format!("            <kind>{}</kind>\n", escape_xml(&check.kind)));
+        xml.push_str(&format!("            <command>{}</command>\n", escape_xml(&check.command)));
+        xml.push_str(&format!("            <args>{}</args>\n", escape_xml(&check.args)));
         xml.push_str("        </check>\n");
     }
     xml.push_str("    </checks>\n");
     xml.push_str("</qa_profile>\n");
 
+    xml
+}
+
+#[tauri::command]
+fn save_qa_profile(profile_data: QAProfileData, file_name: String, app_handle: tauri::AppHandle) -> Result<String, String> {
+    // Determine save path
+    let profiles_dir = if cfg!(dev) {
+        PathBuf::from("../../samples")
+    } else {
+        let resource_dir = app_handle.path()
+            .resource_dir()
+            .map_err(|e| format!("Failed to get resource directory: {}", e))?;
+        resource_dir.join("_up_/_up_/samples")
+    };
+
+    // Ensure directory exists
+    if !profiles_dir.exists() {
+        fs::create_dir_all(&profiles_dir)
+            .map_err(|e| format!("Failed to create profiles directory: {}", e))?;
+    }
+
+    // Build file path
+    let file_path = profiles_dir.join(&file_name);
+
+    if is_json_path(&file_path) {
+        let json = serde_json::to_string_pretty(&profile_data)
+            .map_err(|e| format!("Failed to serialize profile: {}", e))?;
+        fs::write(&file_path, json)
+            .map_err(|e| format!("Failed to write profile file: {}", e))?;
+        return Ok(file_path.to_string_lossy().to_string());
+    }
+
+    // Preserve the original `created` timestamp across re-saves of an
+    // existing profile; only `modified` advances.
+    let existing_created = if file_path.exists() {
+        fs::read_to_string(&file_path)
+            .ok()
+            .and_then(|content| extract_xml_tag(&content, "created"))
+    } else {
+        None
+    };
+
+    let now = time::OffsetDateTime::now_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .map_err(|e| format!("Failed to format timestamp: {}", e))?;
+    let created = existing_created.unwrap_or_else(|| now.clone());
+
+    let xml = build_profile_xml(&profile_data, &created, &now);
+
     // Write to file
     fs::write(&file_path, xml)
         .map_err(|e| format!("Failed to write profile file: {}", e))?;
@@ -714,10 +1089,14 @@ fn delete_qa_profile(profile_path: String) -> Result<(), String> {
 
 #[tauri::command]
 fn load_qa_profile(profile_path: String) -> Result<QAProfileData, String> {
-    // Read XML file
     let xml_content = fs::read_to_string(&profile_path)
         .map_err(|e| format!("Failed to read profile: {}", e))?;
 
+    if is_json_path(std::path::Path::new(&profile_path)) {
+        return serde_json::from_str(&xml_content)
+            .map_err(|e| format!("Failed to parse profile JSON: {}", e));
+    }
+
     // Parse XML
     use quick_xml::events::Event;
     use quick_xml::Reader;
@@ -768,10 +1147,13 @@ fn load_qa_profile(profile_path: String) -> Result<QAProfileData, String> {
                             category: String::new(),
                             case_sensitive: false,
                             exclude_pattern: String::new(),
+                            kind: default_check_kind(),
+                            command: String::new(),
+                            args: String::new(),
                         });
                     }
                     b"name" | b"description" | b"language" | b"pattern" | b"replacement" |
-                    b"category" | b"case_sensitive" | b"exclude_pattern" => {
+                    b"category" | b"case_sensitive" | b"exclude_pattern" | b"kind" | b"command" | b"args" => {
                         current_field = String::from_utf8_lossy(e.name().as_ref()).to_string();
                     }
                     _ => {}
@@ -796,6 +1178,9 @@ fn load_qa_profile(profile_path: String) -> Result<QAProfileData, String> {
                         "category" => check.category = text,
                         "case_sensitive" => check.case_sensitive = text == "true",
                         "exclude_pattern" => check.exclude_pattern = text,
+                        "kind" => check.kind = text,
+                        "command" => check.command = text,
+                        "args" => check.args = text,
                         _ => {}
                     }
                 }
@@ -826,28 +1211,62 @@ fn load_qa_profile(profile_path: String) -> Result<QAProfileData, String> {
     })
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ProfileDiagnostic {
+    check_order: i32,
+    check_name: String,
+    pattern: String,
+    message: String,
+}
+
+/// Compile every regex check's pattern (skipping `external` checks, which
+/// have no pattern to compile) and collect one diagnostic per
+/// failure, so a broken profile is reported all at once instead of failing
+/// the first time a bad check happens to run.
+fn validate_profile_checks(profile: &QAProfileData) -> Vec<ProfileDiagnostic> {
+    profile
+        .checks
+        .iter()
+        .filter(|check| check.kind == "regex")
+        .filter_map(|check| {
+            let result = regex_compat::validate(&check.pattern, &check.replacement);
+            result.error.map(|error| ProfileDiagnostic {
+                check_order: check.order,
+                check_name: check.name.clone(),
+                pattern: check.pattern.clone(),
+                message: error.message,
+            })
+        })
+        .collect()
+}
+
 #[tauri::command]
-fn export_regex_library(library: RegexLibrary, export_path: String) -> Result<String, String> {
-    // Build XML (same as save_regex_library but to custom path)
-    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<regex-library>\n");
+fn validate_qa_profile(profile_path: String) -> Result<Vec<ProfileDiagnostic>, String> {
+    let profile = load_qa_profile(profile_path)?;
+    Ok(validate_profile_checks(&profile))
+}
 
-    for category in library.categories {
-        xml.push_str(&format!("  <category name=\"{}\">\n", escape_xml(&category.name)));
-        for entry in category.entries {
-            xml.push_str("    <entry>\n");
-            xml.push_str(&format!("      <name>{}</name>\n", escape_xml(&entry.name)));
-            xml.push_str(&format!("      <description>{}</description>\n", escape_xml(&entry.description)));
-            xml.push_str(&format!("      <pattern>{}</pattern>\n", escape_xml(&entry.pattern)));
-            xml.push_str(&format!("      <replace>{}</replace>\n", escape_xml(&entry.replace)));
-            xml.push_str("    </entry>\n");
-        }
-        xml.push_str("  </category>\n");
-    }
+#[tauri::command]
+fn validate_regex(pattern: String, replacement: String) -> regex_compat::ValidateRegexResult {
+    regex_compat::validate(&pattern, &replacement)
+}
 
-    xml.push_str("</regex-library>\n");
+#[tauri::command]
+fn preview_regex(pattern: String, replacement: String, sample: String) -> regex_compat::PreviewRegexResult {
+    regex_compat::preview(&pattern, &replacement, &sample)
+}
+
+#[tauri::command]
+fn export_regex_library(library: RegexLibrary, export_path: String) -> Result<String, String> {
+    let content = if is_json_path(std::path::Path::new(&export_path)) {
+        serde_json::to_string_pretty(&library)
+            .map_err(|e| format!("Failed to serialize library: {}", e))?
+    } else {
+        build_library_xml(&library)
+    };
 
     // Write to user-selected path
-    fs::write(&export_path, xml)
+    fs::write(&export_path, content)
         .map_err(|e| format!("Failed to export library: {}", e))?;
 
     Ok(format!("Library exported successfully to {}", export_path))
@@ -855,106 +1274,47 @@ fn export_regex_library(library: RegexLibrary, export_path: String) -> Result<St
 
 #[tauri::command]
 fn import_regex_library(import_path: String) -> Result<RegexLibrary, String> {
-    use quick_xml::events::Event;
-    use quick_xml::Reader;
-
-    // Read the import file
-    let xml_content = fs::read_to_string(&import_path)
+    let content = fs::read_to_string(&import_path)
         .map_err(|e| format!("Failed to read import file: {}", e))?;
 
-    // Parse XML (same logic as load_regex_library)
-    let mut reader = Reader::from_str(&xml_content);
-    reader.trim_text(true);
-
-    let mut buf = Vec::new();
-    let mut categories: Vec<RegexCategory> = Vec::new();
-    let mut current_category: Option<RegexCategory> = None;
-    let mut current_category_name: Option<String> = None;
-    let mut current_entry: Option<RegexEntry> = None;
-    let mut current_field = String::new();
+    let library = if is_json_path(std::path::Path::new(&import_path)) {
+        parse_library_json(&content, "imported")?
+    } else {
+        parse_library_xml(&content, "imported")?
+    };
 
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(ref e)) => {
-                match e.name().as_ref() {
-                    b"category" => {
-                        let name = e.attributes()
-                            .find(|a: &Result<quick_xml::events::attributes::Attribute, _>| {
-                                a.as_ref().map(|attr| attr.key.as_ref() == b"name").unwrap_or(false)
-                            })
-                            .and_then(|a: Result<quick_xml::events::attributes::Attribute, _>| a.ok())
-                            .and_then(|a| String::from_utf8(a.value.to_vec()).ok())
-                            .unwrap_or_else(|| "Uncategorized".to_string());
-                        current_category_name = Some(name.clone());
-                        current_category = Some(RegexCategory {
-                            name,
-                            entries: Vec::new(),
-                        });
-                    }
-                    b"entry" => {
-                        current_entry = Some(RegexEntry {
-                            id: format!("{}", std::time::SystemTime::now()
-                                .duration_since(std::time::UNIX_EPOCH)
-                                .unwrap().as_millis()),
-                            name: String::new(),
-                            description: String::new(),
-                            pattern: String::new(),
-                            replace: String::new(),
-                            category: current_category_name.clone().unwrap_or_else(|| "Uncategorized".to_string()),
-                        });
-                    }
-                    b"name" | b"description" | b"pattern" | b"replace" => {
-                        current_field = String::from_utf8(e.name().as_ref().to_vec()).unwrap_or_default();
-                    }
-                    _ => {}
-                }
-            }
-            Ok(Event::Text(e)) => {
-                if let Some(ref mut entry) = current_entry {
-                    let text = e.unescape().unwrap_or_default().to_string();
-                    match current_field.as_str() {
-                        "name" => entry.name = text,
-                        "description" => entry.description = text,
-                        "pattern" => entry.pattern = text,
-                        "replace" => entry.replace = text,
-                        _ => {}
-                    }
-                }
-            }
-            Ok(Event::End(ref e)) => {
-                match e.name().as_ref() {
-                    b"entry" => {
-                        if let (Some(ref mut category), Some(entry)) = (&mut current_category, current_entry.take()) {
-                            category.entries.push(entry);
-                        }
-                    }
-                    b"category" => {
-                        if let Some(category) = current_category.take() {
-                            categories.push(category);
-                        }
-                        current_category_name = None;
-                    }
-                    _ => {}
-                }
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(format!("Error parsing XML at position {}: {:?}", reader.buffer_position(), e)),
-            _ => {}
-        }
-        buf.clear();
+    let problems = validate_library_patterns(&library);
+    if !problems.is_empty() {
+        return Err(format!("Invalid pattern(s) in import: {}", problems.join("; ")));
     }
 
-    Ok(RegexLibrary { categories })
+    Ok(library)
 }
 
 #[tauri::command]
 fn export_qa_profile(profile_path: String, export_path: String) -> Result<String, String> {
-    // Read the profile file
-    let profile_content = fs::read_to_string(&profile_path)
-        .map_err(|e| format!("Failed to read profile file: {}", e))?;
+    let source_is_json = is_json_path(std::path::Path::new(&profile_path));
+    let dest_is_json = is_json_path(std::path::Path::new(&export_path));
 
-    // Write to export path
-    fs::write(&export_path, profile_content)
+    let content = if source_is_json == dest_is_json {
+        // Same format on both ends: copy the file verbatim.
+        fs::read_to_string(&profile_path)
+            .map_err(|e| format!("Failed to read profile file: {}", e))?
+    } else {
+        // Cross-format export: parse then re-serialize into the target format.
+        let profile = load_qa_profile(profile_path)?;
+        if dest_is_json {
+            serde_json::to_string_pretty(&profile)
+                .map_err(|e| format!("Failed to serialize profile: {}", e))?
+        } else {
+            let now = time::OffsetDateTime::now_utc()
+                .format(&time::format_description::well_known::Rfc3339)
+                .map_err(|e| format!("Failed to format timestamp: {}", e))?;
+            build_profile_xml(&profile, &now, &now)
+        }
+    };
+
+    fs::write(&export_path, content)
         .map_err(|e| format!("Failed to export profile: {}", e))?;
 
     Ok(format!("Profile exported successfully to {}", export_path))
@@ -978,52 +1338,62 @@ fn import_qa_profile(import_path: String) -> Result<String, String> {
     // Read the import file
     let profile_content = fs::read_to_string(&import_path)
         .map_err(|e| format!("Failed to read import file: {}", e))?;
+    let is_json = is_json_path(std::path::Path::new(&import_path));
+    let extension = if is_json { "json" } else { "xml" };
 
-    // Parse to get profile name for filename
-    use quick_xml::events::Event;
-    use quick_xml::Reader;
-
-    let mut reader = Reader::from_str(&profile_content);
-    reader.trim_text(true);
-
-    let mut buf = Vec::new();
-    let mut profile_name = String::new();
-    let mut in_metadata = false;
-    let mut in_name = false;
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(ref e)) => {
-                match e.name().as_ref() {
-                    b"metadata" => in_metadata = true,
-                    b"name" if in_metadata => in_name = true,
-                    _ => {}
+    let profile_name = if is_json {
+        serde_json::from_str::<QAProfileData>(&profile_content)
+            .map(|p| p.name)
+            .map_err(|e| format!("Error parsing JSON: {}", e))?
+    } else {
+        // Parse to get profile name for filename
+        use quick_xml::events::Event;
+        use quick_xml::Reader;
+
+        let mut reader = Reader::from_str(&profile_content);
+        reader.trim_text(true);
+
+        let mut buf = Vec::new();
+        let mut profile_name = String::new();
+        let mut in_metadata = false;
+        let mut in_name = false;
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref e)) => {
+                    match e.name().as_ref() {
+                        b"metadata" => in_metadata = true,
+                        b"name" if in_metadata => in_name = true,
+                        _ => {}
+                    }
                 }
-            }
-            Ok(Event::Text(e)) if in_name => {
-                profile_name = e.unescape().unwrap_or_default().to_string();
-            }
-            Ok(Event::End(ref e)) => {
-                match e.name().as_ref() {
-                    b"metadata" => in_metadata = false,
-                    b"name" => in_name = false,
-                    _ => {}
+                Ok(Event::Text(e)) if in_name => {
+                    profile_name = e.unescape().unwrap_or_default().to_string();
+                }
+                Ok(Event::End(ref e)) => {
+                    match e.name().as_ref() {
+                        b"metadata" => in_metadata = false,
+                        b"name" => in_name = false,
+                        _ => {}
+                    }
                 }
+                Ok(Event::Eof) => break,
+                Err(e) => return Err(format!("Error parsing XML: {:?}", e)),
+                _ => {}
             }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(format!("Error parsing XML: {:?}", e)),
-            _ => {}
+            buf.clear();
         }
-        buf.clear();
-    }
+
+        profile_name
+    };
 
     // Generate filename
     let file_name = if !profile_name.is_empty() {
-        format!("{}_qa_profile.xml", profile_name.to_lowercase().replace(" ", "_"))
+        format!("{}_qa_profile.{}", profile_name.to_lowercase().replace(" ", "_"), extension)
     } else {
-        format!("imported_profile_{}.xml", std::time::SystemTime::now()
+        format!("imported_profile_{}.{}", std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
-            .unwrap().as_secs())
+            .unwrap().as_secs(), extension)
     };
 
     // Create samples directory if it doesn't exist
@@ -1041,6 +1411,26 @@ fn import_qa_profile(import_path: String) -> Result<String, String> {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let cli_args: Vec<String> = std::env::args().skip(1).collect();
+    if cli::looks_like_invocation(&cli_args) {
+        // Strip the explicit `--cli` escape hatch itself before handing
+        // args to clap, which only knows about the subcommands.
+        let args: &[String] = if cli_args.first().map(String::as_str) == Some("--cli") {
+            &cli_args[1..]
+        } else {
+            &cli_args[..]
+        };
+
+        let exit_code = match cli::run(args) {
+            Ok(code) => code,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                1
+            }
+        };
+        std::process::exit(exit_code);
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
@@ -1236,7 +1626,7 @@ pub fn run() {
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![greet, open_xliff, save_xliff, load_regex_library, save_regex_library, batch_find, list_qa_profiles, batch_replace, save_qa_profile, delete_qa_profile, load_qa_profile, export_regex_library, import_regex_library, export_qa_profile, import_qa_profile, get_user_guide_content, get_changelog_content])
+        .invoke_handler(tauri::generate_handler![greet, open_xliff, save_xliff, load_regex_library, save_regex_library, batch_find, list_qa_profiles, batch_replace, save_qa_profile, delete_qa_profile, load_qa_profile, validate_qa_profile, validate_regex, preview_regex, export_regex_library, import_regex_library, import_library_file, export_regex_category, export_qa_profile, import_qa_profile, get_user_guide_content, get_changelog_content])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,264 @@
+// Translation and validation layer bridging the QA profiles' patterns
+// (historically written for Python's `re`, back when `src/cli.py` ran them)
+// and the `regex` crate the native engine now compiles them with.
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UnsupportedConstruct {
+    pub construct: String,
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegexDiagnostic {
+    pub message: String,
+    pub start: Option<usize>,
+    pub end: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValidateRegexResult {
+    pub valid: bool,
+    pub error: Option<RegexDiagnostic>,
+    pub translated_pattern: String,
+    pub translated_replacement: String,
+    pub unsupported: Vec<UnsupportedConstruct>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MatchSpan {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreviewRegexResult {
+    pub valid: bool,
+    pub error: Option<RegexDiagnostic>,
+    pub matches: Vec<MatchSpan>,
+    pub result: Option<String>,
+    pub unsupported: Vec<UnsupportedConstruct>,
+}
+
+/// Rewrite Python named-group syntax `(?P<name>...)` to the `regex` crate's
+/// `(?<name>...)`. Everything else in Python's `re` pattern syntax that the
+/// `regex` crate also understands (character classes, quantifiers, anchors)
+/// passes through unchanged.
+pub fn translate_pattern(pattern: &str) -> String {
+    pattern.replace("(?P<", "(?<")
+}
+
+/// Rewrite Python replacement backreferences (`\1`, `\g<name>`) to the
+/// `regex` crate's `$1` / `${name}` syntax.
+pub fn translate_replacement(replacement: &str) -> String {
+    let mut out = String::with_capacity(replacement.len());
+    let chars: Vec<char> = replacement.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' && i + 1 < chars.len() {
+            let next = chars[i + 1];
+            if next.is_ascii_digit() {
+                // `${1}` rather than bare `$1` so a following alphanumeric
+                // (Python `\1abc`) isn't folded into the group name.
+                out.push_str(&format!("${{{}}}", next));
+                i += 2;
+                continue;
+            }
+            if next == 'g' && chars.get(i + 2) == Some(&'<') {
+                if let Some(close) = chars[i + 3..].iter().position(|c| *c == '>') {
+                    let name: String = chars[i + 3..i + 3 + close].iter().collect();
+                    out.push_str(&format!("${{{}}}", name));
+                    i += 3 + close + 1;
+                    continue;
+                }
+            }
+        }
+        out.push(c);
+        i += 1;
+    }
+
+    out
+}
+
+/// Flag constructs the `regex` crate can't run at all (it's a linear-time
+/// engine with no backtracking), so the UI can warn that a check will only
+/// ever work via the legacy Python CLI fallback.
+pub fn detect_unsupported(pattern: &str) -> Vec<UnsupportedConstruct> {
+    let mut found = Vec::new();
+
+    if pattern.contains("(?=") || pattern.contains("(?!") {
+        found.push(UnsupportedConstruct {
+            construct: "lookahead".to_string(),
+            description: "(?=...) / (?!...) lookahead is not supported by the regex crate".to_string(),
+        });
+    }
+    if pattern.contains("(?<=") || pattern.contains("(?<!") {
+        found.push(UnsupportedConstruct {
+            construct: "lookbehind".to_string(),
+            description: "(?<=...) / (?<!...) lookbehind is not supported by the regex crate".to_string(),
+        });
+    }
+
+    let bytes = pattern.as_bytes();
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'\\' {
+            let next = bytes[i + 1];
+            if next.is_ascii_digit() && next != b'0' {
+                found.push(UnsupportedConstruct {
+                    construct: "backreference".to_string(),
+                    description: format!(
+                        "\\{} backreferences inside the pattern are not supported by the regex crate",
+                        next as char
+                    ),
+                });
+                break;
+            }
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    found
+}
+
+fn syntax_diagnostic(pattern: &str) -> Option<RegexDiagnostic> {
+    use regex_syntax::ast::parse::Parser;
+
+    match Parser::new().parse(pattern) {
+        Ok(_) => None,
+        Err(e) => {
+            let span = e.span();
+            Some(RegexDiagnostic {
+                message: e.kind().to_string(),
+                start: Some(span.start.offset),
+                end: Some(span.end.offset),
+            })
+        }
+    }
+}
+
+/// Compile `pattern` (after translating Python `re` syntax) and report either
+/// success or a diagnostic with as precise a position as `regex-syntax` can
+/// recover. `replacement` is only translated, not checked.
+pub fn validate(pattern: &str, replacement: &str) -> ValidateRegexResult {
+    let translated_pattern = translate_pattern(pattern);
+    let translated_replacement = translate_replacement(replacement);
+    let unsupported = detect_unsupported(pattern);
+
+    let error = match regex::Regex::new(&translated_pattern) {
+        Ok(_) => None,
+        Err(e) => Some(syntax_diagnostic(&translated_pattern).unwrap_or(RegexDiagnostic {
+            message: e.to_string(),
+            start: None,
+            end: None,
+        })),
+    };
+
+    ValidateRegexResult {
+        valid: error.is_none(),
+        error,
+        translated_pattern,
+        translated_replacement,
+        unsupported,
+    }
+}
+
+/// Compile `pattern` and run it against `sample`, returning every match span
+/// plus the text with `replacement` applied, so the UI can highlight both
+/// in a live preview.
+pub fn preview(pattern: &str, replacement: &str, sample: &str) -> PreviewRegexResult {
+    let translated_pattern = translate_pattern(pattern);
+    let translated_replacement = translate_replacement(replacement);
+    let unsupported = detect_unsupported(pattern);
+
+    let re = match regex::Regex::new(&translated_pattern) {
+        Ok(re) => re,
+        Err(e) => {
+            let error = syntax_diagnostic(&translated_pattern).unwrap_or(RegexDiagnostic {
+                message: e.to_string(),
+                start: None,
+                end: None,
+            });
+            return PreviewRegexResult {
+                valid: false,
+                error: Some(error),
+                matches: Vec::new(),
+                result: None,
+                unsupported,
+            };
+        }
+    };
+
+    let matches = re
+        .find_iter(sample)
+        .map(|m| MatchSpan {
+            start: m.start(),
+            end: m.end(),
+            text: m.as_str().to_string(),
+        })
+        .collect();
+
+    let result = re.replace_all(sample, translated_replacement.as_str()).to_string();
+
+    PreviewRegexResult {
+        valid: true,
+        error: None,
+        matches,
+        result: Some(result),
+        unsupported,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translate_pattern_rewrites_named_groups() {
+        assert_eq!(translate_pattern(r"(?P<word>\w+)"), r"(?<word>\w+)");
+    }
+
+    #[test]
+    fn translate_replacement_braces_digit_backrefs_to_avoid_alnum_ambiguity() {
+        // Bare `$1abc` would read as the named group `1abc` to the `regex`
+        // crate; `${1}abc` keeps group 1 and the literal `abc` distinct.
+        assert_eq!(translate_replacement(r"\1abc"), "${1}abc");
+        assert_eq!(translate_replacement(r"\1 \2"), "${1} ${2}");
+    }
+
+    #[test]
+    fn translate_replacement_rewrites_named_backrefs() {
+        assert_eq!(translate_replacement(r"\g<word>!"), "${word}!");
+    }
+
+    #[test]
+    fn detect_unsupported_flags_lookaround_and_backreferences() {
+        let found = detect_unsupported(r"foo(?=bar)");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].construct, "lookahead");
+
+        let found = detect_unsupported(r"(\w+)\1");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].construct, "backreference");
+    }
+
+    #[test]
+    fn validate_reports_syntax_errors() {
+        let result = validate("(unclosed", "");
+        assert!(!result.valid);
+        assert!(result.error.is_some());
+    }
+
+    #[test]
+    fn validate_accepts_translated_python_syntax() {
+        let result = validate(r"(?P<word>\w+)", r"\g<word>");
+        assert!(result.valid);
+        assert_eq!(result.translated_pattern, r"(?<word>\w+)");
+        assert_eq!(result.translated_replacement, "${word}");
+    }
+}
@@ -0,0 +1,803 @@
+// Native XLIFF 1.2 / 2.0 engine: parsing, in-place edit application, and
+// regex batch find/replace. This replaces the old `src/cli.py` subprocess —
+// no temp files, no JSON-over-stdout, just quick-xml directly against the
+// document.
+use std::io::Cursor;
+use std::process::Command;
+
+use quick_xml::events::{BytesText, Event};
+use quick_xml::{Reader, Writer};
+use serde::{Deserialize, Serialize};
+use tempfile::NamedTempFile;
+
+use crate::regex_compat;
+use crate::{QACheckData, QAProfileData};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Metadata {
+    pub match_percent: Option<String>,
+    pub match_quality: Option<String>,
+    pub translate: Option<String>,
+    pub approved: Option<String>,
+    pub modified_date: Option<String>,
+    pub modified_by: Option<String>,
+    pub state: Option<String>,
+    pub locked: Option<String>,
+    pub created_date: Option<String>,
+    pub created_by: Option<String>,
+    pub origin: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransUnit {
+    pub id: String,
+    pub source: String,
+    pub target: String,
+    pub metadata: Option<Metadata>,
+    pub icu_errors: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct XliffData {
+    pub trans_units: Vec<TransUnit>,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub total_units: i32,
+    pub translated: i32,
+    pub untranslated: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EditedUnit {
+    pub id: String,
+    pub target: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchFindMatch {
+    pub tu_id: String,
+    pub check_name: String,
+    pub check_order: i32,
+    pub category: String,
+    pub description: String,
+    pub source: String,
+    pub target: String,
+    #[serde(rename = "match")]
+    pub match_text: String,
+    pub match_start: i32,
+    pub match_end: i32,
+    pub pattern: String,
+    pub replacement: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchFindResult {
+    pub profile_name: String,
+    pub file: String,
+    pub total_matches: i32,
+    pub matches: Vec<BatchFindMatch>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchReplaceResult {
+    pub success: bool,
+    pub modified_units: i32,
+    pub total_replacements: i32,
+    pub output_path: String,
+    /// Populated instead of `output_path` when the caller passed in-memory
+    /// content rather than a file path.
+    #[serde(default)]
+    pub content: Option<String>,
+}
+
+/// A `<trans-unit>` (1.2) or `<unit>`/`<segment>` (2.0) element, tracked
+/// while walking the document so we know which text run belongs to which
+/// unit/field.
+#[derive(Default)]
+struct UnitCursor {
+    id: String,
+    in_source: bool,
+    in_target: bool,
+    source: String,
+    target: String,
+    metadata: Metadata,
+    target_attrs_seen: bool,
+}
+
+impl Metadata {
+    fn empty() -> Self {
+        Metadata {
+            match_percent: None,
+            match_quality: None,
+            translate: None,
+            approved: None,
+            modified_date: None,
+            modified_by: None,
+            state: None,
+            locked: None,
+            created_date: None,
+            created_by: None,
+            origin: None,
+        }
+    }
+}
+
+fn attr_value(e: &quick_xml::events::BytesStart, key: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == key)
+        .and_then(|a| String::from_utf8(a.value.to_vec()).ok())
+}
+
+/// Parse XLIFF content (1.2 `trans-unit` or 2.0 `unit`/`segment` elements)
+/// into the same `TransUnit`/`Stats` shape the Python CLI used to hand back.
+/// `Metadata` is filled from the same attributes the CLI read: `translate`/
+/// `approved` off the unit, `state`/`match-quality` off `<target>` (the
+/// latter is part of the XLIFF 1.2 core spec), and the remaining
+/// match/revision-bookkeeping fields off whatever flat kebab-case
+/// attributes the authoring tool put on the unit itself.
+pub fn parse_xliff(content: &str) -> Result<XliffData, String> {
+    let mut reader = Reader::from_str(content);
+    reader.trim_text(false);
+
+    let mut trans_units = Vec::new();
+    let mut cursor: Option<UnitCursor> = None;
+    let mut in_alt_trans = false;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"alt-trans" => {
+                // Alternate translations aren't surfaced as separate
+                // units; skip their contents entirely.
+                in_alt_trans = true;
+            }
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+                match e.name().as_ref() {
+                    b"trans-unit" | b"unit" => {
+                        let id = attr_value(e, b"id").unwrap_or_default();
+                        let mut metadata = Metadata::empty();
+                        metadata.translate = attr_value(e, b"translate");
+                        metadata.approved = attr_value(e, b"approved");
+                        // Not part of the XLIFF 1.2/2.0 core spec, but the
+                        // same flat kebab-case attribute names the old
+                        // Python CLI read off `trans-unit` for tool-applied
+                        // match/revision bookkeeping.
+                        metadata.match_percent = attr_value(e, b"match-percent");
+                        metadata.modified_date = attr_value(e, b"modified-date");
+                        metadata.modified_by = attr_value(e, b"modified-by");
+                        metadata.locked = attr_value(e, b"locked");
+                        metadata.created_date = attr_value(e, b"created-date");
+                        metadata.created_by = attr_value(e, b"created-by");
+                        metadata.origin = attr_value(e, b"origin");
+                        cursor = Some(UnitCursor {
+                            id,
+                            metadata,
+                            ..Default::default()
+                        });
+                    }
+                    b"source" | b"seg-source" if !in_alt_trans => {
+                        if let Some(ref mut c) = cursor {
+                            c.in_source = true;
+                        }
+                    }
+                    b"target" if !in_alt_trans => {
+                        if let Some(ref mut c) = cursor {
+                            c.in_target = true;
+                            if !c.target_attrs_seen {
+                                c.target_attrs_seen = true;
+                                c.metadata.state = attr_value(e, b"state");
+                                // `match-quality` is a standard XLIFF 1.2
+                                // `<target>` attribute (leveraged TM match
+                                // percentage/description).
+                                c.metadata.match_quality = attr_value(e, b"match-quality");
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().unwrap_or_default().to_string();
+                if let Some(ref mut c) = cursor {
+                    if c.in_source {
+                        c.source.push_str(&text);
+                    } else if c.in_target {
+                        c.target.push_str(&text);
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) => match e.name().as_ref() {
+                b"alt-trans" => in_alt_trans = false,
+                b"source" | b"seg-source" => {
+                    if let Some(ref mut c) = cursor {
+                        c.in_source = false;
+                    }
+                }
+                b"target" => {
+                    if let Some(ref mut c) = cursor {
+                        c.in_target = false;
+                    }
+                }
+                b"trans-unit" | b"unit" => {
+                    if let Some(c) = cursor.take() {
+                        trans_units.push(TransUnit {
+                            id: c.id,
+                            source: c.source,
+                            target: c.target,
+                            metadata: Some(c.metadata),
+                            icu_errors: None,
+                        });
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(format!("XML parse error at {}: {}", reader.buffer_position(), e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let total_units = trans_units.len() as i32;
+    let translated = trans_units
+        .iter()
+        .filter(|tu| !tu.target.trim().is_empty())
+        .count() as i32;
+
+    Ok(XliffData {
+        trans_units,
+        stats: Stats {
+            total_units,
+            translated,
+            untranslated: total_units - translated,
+        },
+    })
+}
+
+fn find_edit<'a>(unit_id: &Option<String>, edited_units: &'a [EditedUnit]) -> Option<&'a EditedUnit> {
+    unit_id.as_deref().and_then(|id| edited_units.iter().find(|u| u.id == id))
+}
+
+/// Rewrite `content`, replacing the text of each `<target>` whose enclosing
+/// `trans-unit`/`unit` id matches an entry in `edited_units`. Every
+/// attribute on the surrounding elements is copied through untouched,
+/// self-closing elements included. Only the first text run directly inside
+/// `<target>` is rewritten with the new translation, since the caller only
+/// ever hands back a single flattened string per unit; a target with no
+/// text run at all (`<target/>` or `<target></target>`) gets the
+/// replacement inserted on close.
+///
+/// A flattened string has nowhere to put inline tags (`<g>`, `<x/>`,
+/// `<ph>`) back, so overwriting a target that contains any is refused
+/// outright rather than silently merging the new text with stale tag
+/// content — callers should resolve those units some other way (e.g.
+/// editing the structured fragment, or skipping the unit).
+pub fn apply_edits(content: &str, edited_units: &[EditedUnit]) -> Result<String, String> {
+    let mut reader = Reader::from_str(content);
+    reader.trim_text(false);
+
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    let mut current_unit_id: Option<String> = None;
+    let mut in_target = false;
+    let mut target_written = false;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                match e.name().as_ref() {
+                    b"trans-unit" | b"unit" => {
+                        current_unit_id = attr_value(e, b"id");
+                    }
+                    b"target" => {
+                        in_target = true;
+                        target_written = false;
+                    }
+                    name => {
+                        if in_target && find_edit(&current_unit_id, edited_units).is_some() {
+                            return Err(format!(
+                                "Unit {} has an inline tag (<{}>) inside <target>; refusing to overwrite it with a flattened translation",
+                                current_unit_id.as_deref().unwrap_or("?"),
+                                String::from_utf8_lossy(name)
+                            ));
+                        }
+                    }
+                }
+                writer
+                    .write_event(Event::Start(e.clone()))
+                    .map_err(|e| format!("Failed to write XML: {}", e))?;
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"target" => {
+                match find_edit(&current_unit_id, edited_units) {
+                    Some(edit) => {
+                        // An empty `<target/>` has no text run to overwrite;
+                        // expand it into start/text/end so the new
+                        // translation has somewhere to live.
+                        writer
+                            .write_event(Event::Start(e.clone()))
+                            .map_err(|e| format!("Failed to write XML: {}", e))?;
+                        writer
+                            .write_event(Event::Text(BytesText::new(&edit.target)))
+                            .map_err(|e| format!("Failed to write XML: {}", e))?;
+                        writer
+                            .write_event(Event::End(e.to_end()))
+                            .map_err(|e| format!("Failed to write XML: {}", e))?;
+                    }
+                    None => {
+                        writer
+                            .write_event(Event::Empty(e.clone()))
+                            .map_err(|e| format!("Failed to write XML: {}", e))?;
+                    }
+                }
+            }
+            Ok(Event::Empty(ref e)) => {
+                if in_target && find_edit(&current_unit_id, edited_units).is_some() {
+                    return Err(format!(
+                        "Unit {} has an inline tag (<{}/>) inside <target>; refusing to overwrite it with a flattened translation",
+                        current_unit_id.as_deref().unwrap_or("?"),
+                        String::from_utf8_lossy(e.name().as_ref())
+                    ));
+                }
+                writer
+                    .write_event(Event::Empty(e.clone()))
+                    .map_err(|e| format!("Failed to write XML: {}", e))?;
+            }
+            Ok(Event::Text(e)) => {
+                let replacement = find_edit(&current_unit_id, edited_units);
+
+                if in_target && replacement.is_some() && !target_written {
+                    target_written = true;
+                    let new_text = BytesText::new(&replacement.unwrap().target);
+                    writer
+                        .write_event(Event::Text(new_text))
+                        .map_err(|e| format!("Failed to write XML: {}", e))?;
+                } else {
+                    writer
+                        .write_event(Event::Text(e))
+                        .map_err(|e| format!("Failed to write XML: {}", e))?;
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                if e.name().as_ref() == b"target" {
+                    if in_target && !target_written {
+                        if let Some(edit) = find_edit(&current_unit_id, edited_units) {
+                            writer
+                                .write_event(Event::Text(BytesText::new(&edit.target)))
+                                .map_err(|e| format!("Failed to write XML: {}", e))?;
+                        }
+                    }
+                    in_target = false;
+                }
+                writer
+                    .write_event(Event::End(e.clone()))
+                    .map_err(|e| format!("Failed to write XML: {}", e))?;
+            }
+            Ok(Event::Eof) => break,
+            Ok(event) => {
+                writer
+                    .write_event(event)
+                    .map_err(|e| format!("Failed to write XML: {}", e))?;
+            }
+            Err(e) => return Err(format!("XML parse error at {}: {}", reader.buffer_position(), e)),
+        }
+        buf.clear();
+    }
+
+    String::from_utf8(writer.into_inner().into_inner())
+        .map_err(|e| format!("Generated XML was not valid UTF-8: {}", e))
+}
+
+/// Run `check`'s pattern against `text` and return each match as
+/// `(start, end, matched_text)`, with `start`/`end` in **char** offsets
+/// (matching the old Python `re`-based CLI's contract) rather than the
+/// `regex` crate's native byte offsets — this tool's segments are routinely
+/// non-ASCII (Norwegian æ/ø/å), so the two diverge as soon as a match isn't
+/// at the very start of the text.
+fn run_check(check: &QACheckData, text: &str) -> Vec<(usize, usize, String)> {
+    let translated = regex_compat::translate_pattern(&check.pattern);
+    let pattern = if check.case_sensitive {
+        translated
+    } else {
+        format!("(?i){}", translated)
+    };
+
+    let re = match regex::Regex::new(&pattern) {
+        Ok(re) => re,
+        Err(_) => return Vec::new(),
+    };
+
+    let exclude_re = if check.exclude_pattern.trim().is_empty() {
+        None
+    } else {
+        regex::Regex::new(&check.exclude_pattern).ok()
+    };
+
+    re.find_iter(text)
+        .filter(|m| {
+            exclude_re
+                .as_ref()
+                .map(|ex| !ex.is_match(m.as_str()))
+                .unwrap_or(true)
+        })
+        .map(|m| {
+            let start = text[..m.start()].chars().count();
+            let end = start + text[m.start()..m.end()].chars().count();
+            (start, end, m.as_str().to_string())
+        })
+        .collect()
+}
+
+/// Run an "external" check: write `tu`'s source and target to temp files,
+/// invoke `check.command` with `check.args` (after substituting `{source}`/
+/// `{target}` with the temp file paths), and interpret the result. Stdout
+/// lines of the form `start:end` are taken as byte-offset match ranges into
+/// the target text; with no such lines, a non-zero exit is treated as a
+/// single failure spanning the whole target. Spans are reported in char
+/// offsets, mirroring the regex checks' match-span shape so both kinds feed
+/// the same `BatchFindMatch` reporting.
+fn run_external_check(check: &QACheckData, tu: &TransUnit) -> Vec<(usize, usize, String)> {
+    let source_file = match NamedTempFile::new() {
+        Ok(f) => f,
+        Err(_) => return Vec::new(),
+    };
+    let target_file = match NamedTempFile::new() {
+        Ok(f) => f,
+        Err(_) => return Vec::new(),
+    };
+
+    if std::fs::write(source_file.path(), &tu.source).is_err()
+        || std::fs::write(target_file.path(), &tu.target).is_err()
+    {
+        return Vec::new();
+    }
+
+    let source_path = source_file.path().to_string_lossy().to_string();
+    let target_path = target_file.path().to_string_lossy().to_string();
+    let args: Vec<String> = check
+        .args
+        .split_whitespace()
+        .map(|arg| arg.replace("{source}", &source_path).replace("{target}", &target_path))
+        .collect();
+
+    let output = match Command::new(&check.command).args(&args).output() {
+        Ok(output) => output,
+        Err(_) => return Vec::new(),
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let ranges: Vec<(usize, usize, String)> = stdout
+        .lines()
+        .filter_map(|line| {
+            let (start, end) = line.split_once(':')?;
+            let start: usize = start.trim().parse().ok()?;
+            let end: usize = end.trim().parse().ok()?;
+            let text = tu.target.get(start..end)?.to_string();
+            let char_start = tu.target[..start].chars().count();
+            let char_end = char_start + text.chars().count();
+            Some((char_start, char_end, text))
+        })
+        .collect();
+
+    if !ranges.is_empty() {
+        return ranges;
+    }
+
+    if !output.status.success() {
+        return vec![(0, tu.target.chars().count(), tu.target.clone())];
+    }
+
+    Vec::new()
+}
+
+/// Run every enabled check in `profile` against a single trans-unit. Split
+/// out from `find_matches` so a caller that wants per-unit progress (the
+/// `batch_find` Tauri command) can drive the same matching logic one unit
+/// at a time.
+pub fn check_unit(tu: &TransUnit, profile: &QAProfileData) -> Vec<BatchFindMatch> {
+    let mut matches = Vec::new();
+
+    for check in profile.checks.iter().filter(|c| c.enabled) {
+        let check_matches = if check.kind == "external" {
+            run_external_check(check, tu)
+        } else {
+            run_check(check, &tu.target)
+        };
+
+        for (start, end, text) in check_matches {
+            matches.push(BatchFindMatch {
+                tu_id: tu.id.clone(),
+                check_name: check.name.clone(),
+                check_order: check.order,
+                category: check.category.clone(),
+                description: check.description.clone(),
+                source: tu.source.clone(),
+                target: tu.target.clone(),
+                match_text: text,
+                match_start: start as i32,
+                match_end: end as i32,
+                pattern: check.pattern.clone(),
+                replacement: check.replacement.clone(),
+            });
+        }
+    }
+
+    matches
+}
+
+/// Scan every trans-unit's target text against every enabled check in
+/// `profile` and report the matches, mirroring the old `batch-find --json`
+/// shape the CLI used to produce.
+pub fn find_matches(file: &str, content: &str, profile: &QAProfileData) -> Result<BatchFindResult, String> {
+    let xliff = parse_xliff(content)?;
+    let mut matches = Vec::new();
+
+    for tu in &xliff.trans_units {
+        matches.extend(check_unit(tu, profile));
+    }
+
+    Ok(BatchFindResult {
+        profile_name: profile.name.clone(),
+        file: file.to_string(),
+        total_matches: matches.len() as i32,
+        matches,
+    })
+}
+
+/// Apply every enabled check's regex replacement to a single trans-unit's
+/// target text. Honors `exclude_pattern` the same way `run_check` does for
+/// find: a match whose text hits `exclude_pattern` is left untouched rather
+/// than replaced. Returns `None` when no (non-excluded) check matched, so
+/// the caller can skip emitting an edit (and, in the streaming command,
+/// skip counting the unit as modified).
+pub fn replace_unit(tu: &TransUnit, profile: &QAProfileData) -> Option<(String, i32)> {
+    let mut new_target = tu.target.clone();
+    let mut replacements = 0;
+
+    for check in profile.checks.iter().filter(|c| c.enabled && c.kind != "external") {
+        let translated = regex_compat::translate_pattern(&check.pattern);
+        let pattern = if check.case_sensitive {
+            translated
+        } else {
+            format!("(?i){}", translated)
+        };
+        let re = match regex::Regex::new(&pattern) {
+            Ok(re) => re,
+            Err(_) => continue,
+        };
+
+        let exclude_re = if check.exclude_pattern.trim().is_empty() {
+            None
+        } else {
+            regex::Regex::new(&check.exclude_pattern).ok()
+        };
+
+        let replacement = regex_compat::translate_replacement(&check.replacement);
+
+        let mut rewritten = String::with_capacity(new_target.len());
+        let mut last_end = 0;
+        let mut count = 0;
+
+        for caps in re.captures_iter(&new_target) {
+            let m = caps.get(0).expect("whole-match group 0 always present");
+            if exclude_re.as_ref().map(|ex| ex.is_match(m.as_str())).unwrap_or(false) {
+                continue;
+            }
+            rewritten.push_str(&new_target[last_end..m.start()]);
+            caps.expand(&replacement, &mut rewritten);
+            last_end = m.end();
+            count += 1;
+        }
+        rewritten.push_str(&new_target[last_end..]);
+
+        if count > 0 {
+            new_target = rewritten;
+            replacements += count;
+        }
+    }
+
+    if replacements > 0 {
+        Some((new_target, replacements))
+    } else {
+        None
+    }
+}
+
+/// Apply every enabled check's regex replacement across all trans-unit
+/// targets and return the rewritten document alongside a summary. Fails
+/// outright (see `apply_edits`) if any modified unit's target contains
+/// inline tags.
+pub fn apply_replacements(content: &str, profile: &QAProfileData) -> Result<(String, BatchReplaceResult), String> {
+    let xliff = parse_xliff(content)?;
+
+    let mut modified_units = 0;
+    let mut total_replacements = 0;
+    let mut edits = Vec::new();
+
+    for tu in &xliff.trans_units {
+        if let Some((new_target, count)) = replace_unit(tu, profile) {
+            modified_units += 1;
+            total_replacements += count;
+            edits.push(EditedUnit {
+                id: tu.id.clone(),
+                target: new_target,
+            });
+        }
+    }
+
+    let new_content = apply_edits(content, &edits)?;
+
+    Ok((
+        new_content,
+        BatchReplaceResult {
+            success: true,
+            modified_units,
+            total_replacements,
+            output_path: String::new(),
+            content: None,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QACheckData, QAProfileData};
+
+    fn regex_check(pattern: &str, replacement: &str, exclude_pattern: &str) -> QACheckData {
+        QACheckData {
+            order: 0,
+            enabled: true,
+            name: "test check".to_string(),
+            description: String::new(),
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            category: String::new(),
+            case_sensitive: false,
+            exclude_pattern: exclude_pattern.to_string(),
+            kind: "regex".to_string(),
+            command: String::new(),
+            args: String::new(),
+        }
+    }
+
+    fn profile(checks: Vec<QACheckData>) -> QAProfileData {
+        QAProfileData {
+            name: "test profile".to_string(),
+            description: String::new(),
+            language: "en".to_string(),
+            checks,
+        }
+    }
+
+    #[test]
+    fn parse_xliff_reads_source_and_target() {
+        let xliff = parse_xliff(
+            r#"<xliff><file><body>
+                <trans-unit id="1"><source>Hello</source><target>Hei</target></trans-unit>
+            </body></file></xliff>"#,
+        )
+        .unwrap();
+
+        assert_eq!(xliff.trans_units.len(), 1);
+        assert_eq!(xliff.trans_units[0].source, "Hello");
+        assert_eq!(xliff.trans_units[0].target, "Hei");
+        assert_eq!(xliff.stats.translated, 1);
+    }
+
+    #[test]
+    fn apply_edits_rewrites_flat_target_text() {
+        let content = r#"<trans-unit id="1"><source>Hello</source><target>Hei</target></trans-unit>"#;
+        let edits = vec![EditedUnit {
+            id: "1".to_string(),
+            target: "Hallo".to_string(),
+        }];
+
+        let result = apply_edits(content, &edits).unwrap();
+        assert!(result.contains("<target>Hallo</target>"));
+    }
+
+    #[test]
+    fn apply_edits_refuses_to_overwrite_a_target_with_inline_tags() {
+        let content = r#"<trans-unit id="1"><target>Se <ph id="1">X</ph> her</target></trans-unit>"#;
+        let edits = vec![EditedUnit {
+            id: "1".to_string(),
+            target: "NEW".to_string(),
+        }];
+
+        let err = apply_edits(content, &edits).unwrap_err();
+        assert!(err.contains("inline tag"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn apply_edits_leaves_unedited_units_with_inline_tags_alone() {
+        let content = r#"<trans-unit id="1"><target>Se <ph id="1">X</ph> her</target></trans-unit>
+<trans-unit id="2"><target>Plain</target></trans-unit>"#;
+        let edits = vec![EditedUnit {
+            id: "2".to_string(),
+            target: "Enkel".to_string(),
+        }];
+
+        let result = apply_edits(content, &edits).unwrap();
+        assert!(result.contains(r#"<target>Se <ph id="1">X</ph> her</target>"#));
+        assert!(result.contains("<target>Enkel</target>"));
+    }
+
+    #[test]
+    fn apply_edits_fills_in_empty_target() {
+        let self_closing = r#"<trans-unit id="1"><target/></trans-unit>"#;
+        let edits = vec![EditedUnit {
+            id: "1".to_string(),
+            target: "Filled in".to_string(),
+        }];
+        let result = apply_edits(self_closing, &edits).unwrap();
+        assert!(result.contains("<target>Filled in</target>"));
+
+        let open_close = r#"<trans-unit id="1"><target></target></trans-unit>"#;
+        let result = apply_edits(open_close, &edits).unwrap();
+        assert!(result.contains("<target>Filled in</target>"));
+    }
+
+    #[test]
+    fn apply_edits_does_not_expand_untouched_empty_elements() {
+        let content = r#"<body>
+<trans-unit id="1"><source>Hello <x id="1"/></source><target>Hei <x id="1"/></target></trans-unit>
+<trans-unit id="2"><source>World</source><target>Verden</target></trans-unit>
+</body>"#;
+        let edits = vec![EditedUnit {
+            id: "2".to_string(),
+            target: "Jorden".to_string(),
+        }];
+
+        let result = apply_edits(content, &edits).unwrap();
+        assert!(result.contains(r#"<x id="1"/>"#));
+        assert!(!result.contains(r#"<x id="1"></x>"#));
+    }
+
+    #[test]
+    fn replace_unit_respects_exclude_pattern() {
+        let tu = TransUnit {
+            id: "1".to_string(),
+            source: String::new(),
+            target: "foo bar FOOBAR foo".to_string(),
+            metadata: None,
+            icu_errors: None,
+        };
+        let check = regex_check(r"foo\w*", "baz", "FOOBAR");
+        let profile = profile(vec![check]);
+
+        let (new_target, count) = replace_unit(&tu, &profile).unwrap();
+        assert_eq!(new_target, "baz bar FOOBAR baz");
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn replace_unit_returns_none_when_every_match_is_excluded() {
+        let tu = TransUnit {
+            id: "1".to_string(),
+            source: String::new(),
+            target: "FOOBAR".to_string(),
+            metadata: None,
+            icu_errors: None,
+        };
+        let check = regex_check(r"foo\w*", "baz", "FOOBAR");
+        let profile = profile(vec![check]);
+
+        assert!(replace_unit(&tu, &profile).is_none());
+    }
+
+    #[test]
+    fn run_check_reports_char_offsets_not_byte_offsets() {
+        // "æøå " is 4 chars but 7 bytes, so a byte offset for "ost" would
+        // overstate where it starts in the char-indexed string the frontend
+        // highlights against.
+        let check = regex_check(r"ost", "", "");
+        let matches = run_check(&check, "æøå ost");
+
+        assert_eq!(matches, vec![(4, 7, "ost".to_string())]);
+    }
+}